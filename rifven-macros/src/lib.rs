@@ -0,0 +1,154 @@
+//! Procedural macros backing `rifven`'s `macros` feature.
+//!
+//! This crate is not meant to be depended on directly; use it through
+//! `rifven::rif!` instead.
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, LitStr};
+
+/// Length of a RIF identifier. Mirrors `rifven::rif::RIF_IDENTIFIER_LENGTH`.
+const RIF_IDENTIFIER_LENGTH: u32 = 9;
+
+/// Parses and checksum-validates a RIF literal at compile time, expanding
+/// to a `rifven::Rif` built from its validated `Kind`, identifier and
+/// checksum digit.
+///
+/// ```ignore
+/// use rifven::rif;
+///
+/// let rif = rif!("J-07013380-5");
+/// ```
+///
+/// A malformed literal or a wrong check digit raises a `compile_error!`
+/// with the same wording `rifven::Error` would produce at runtime.
+#[proc_macro]
+pub fn rif(input: TokenStream) -> TokenStream {
+    let literal = parse_macro_input!(input as LitStr);
+    let value = literal.value();
+    let parts: Vec<&str> = value.split('-').collect();
+
+    if parts.len() != 3 {
+        return compile_error(
+            &literal,
+            format!(
+                "Invalid RIF. RIF must be splitted into 3 parts separated by dashes. Eg. J-123456789-1. Provided {}",
+                value
+            ),
+        );
+    }
+
+    let kind_str = parts[0];
+    let kind_checksum_digit = match kind_str.to_lowercase().as_str() {
+        "v" => 1,
+        "e" => 2,
+        "j" | "c" => 3,
+        "p" => 4,
+        "g" => 5,
+        _ => {
+            return compile_error(
+                &literal,
+                format!(
+                    "Invalid RIF Kind provided, {}. Expected one of \"E, G, J, P, V\"",
+                    kind_str
+                ),
+            )
+        }
+    };
+
+    let kind_variant = match kind_str.to_lowercase().as_str() {
+        "c" => quote!(rifven::Kind::Township),
+        "e" => quote!(rifven::Kind::Foreigner),
+        "g" => quote!(rifven::Kind::Government),
+        "j" => quote!(rifven::Kind::Legal),
+        "p" => quote!(rifven::Kind::Passport),
+        "v" => quote!(rifven::Kind::Venezuelan),
+        _ => unreachable!("kind already validated above"),
+    };
+
+    let identifier: u32 = match parts[1].parse() {
+        Ok(identifier) => identifier,
+        Err(e) => {
+            return compile_error(
+                &literal,
+                format!("Invalid RIF identifier provided. {}", e),
+            )
+        }
+    };
+
+    let checksum_digit: u8 = match parts[2].parse() {
+        Ok(checksum_digit) => checksum_digit,
+        Err(_) => {
+            return compile_error(
+                &literal,
+                format!(
+                    "The provided check number is not a valid digit. Received: {}",
+                    parts[2]
+                ),
+            )
+        }
+    };
+
+    let expected_checksum_digit = calc_checksum_digit(kind_checksum_digit, identifier);
+
+    if expected_checksum_digit != checksum_digit {
+        return compile_error(
+            &literal,
+            format!(
+                "Invalid check num provided, expected {} and received {}",
+                expected_checksum_digit, checksum_digit
+            ),
+        );
+    }
+
+    quote! {
+        rifven::Rif::new(#kind_variant, #identifier, #checksum_digit)
+            .expect("rif! literal validated at compile time")
+    }
+    .into()
+}
+
+/// Mirrors `rifven::Rif::calc_checksum_digit`, operating on the `Kind`'s
+/// own checksum digit instead of a `Kind` value since `syn`/`quote` run
+/// before `rifven`'s types exist in this crate's dependency graph.
+fn calc_checksum_digit(kind_checksum_digit: u32, identifier: u32) -> u8 {
+    let length = RIF_IDENTIFIER_LENGTH as usize;
+    let mut digits = vec![0u32; length];
+    let mut identifier = identifier;
+
+    for idx in 1..=length {
+        digits[length - idx] = identifier % 10;
+        identifier /= 10;
+    }
+
+    let mut sum_values = vec![0u32; length];
+
+    for (idx, digit) in digits.into_iter().enumerate() {
+        sum_values[idx] = match idx {
+            0 => kind_checksum_digit * 4,
+            1 | 7 => digit * 3,
+            2 | 8 => digit * 2,
+            3 => digit * 7,
+            4 => digit * 6,
+            5 => digit * 5,
+            6 => digit * 4,
+            _ => 0,
+        };
+    }
+
+    let sum_values_total: u32 = sum_values.iter().sum();
+    let validator = sum_values_total / 11;
+    let reminder = sum_values_total - (validator * 11);
+    let checksum_digit = 11 - reminder;
+
+    if checksum_digit > 9 {
+        return 0;
+    }
+
+    checksum_digit as u8
+}
+
+fn compile_error(literal: &LitStr, message: String) -> TokenStream {
+    syn::Error::new(literal.span(), message)
+        .to_compile_error()
+        .into()
+}
@@ -1,6 +1,9 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
 use thiserror::Error as ThisError;
 
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = core::result::Result<T, Error>;
 
 #[derive(Debug, PartialEq, ThisError)]
 pub enum Error {
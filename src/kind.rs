@@ -1,6 +1,13 @@
+#[cfg(feature = "std")]
 use std::str::FromStr;
+#[cfg(feature = "std")]
 use std::string::ToString;
 
+#[cfg(not(feature = "std"))]
+use alloc::string::ToString;
+#[cfg(not(feature = "std"))]
+use core::str::FromStr;
+
 use crate::error::Error;
 
 /// The RIF kind represents the kind of entity this RIF
@@ -10,15 +17,16 @@ use crate::error::Error;
 ///
 /// - C: Township or Communal Council
 /// - E: Represents a foreigner natural person and stands for
-/// "Extranjera" and "Extranjero"
+///   "Extranjera" and "Extranjero"
 /// - G: Represents a goverment entity and stands for
-/// "Gubernamental"
+///   "Gubernamental"
 /// - J: Used for a legal entity. Could be a natural person
-/// or a corporate entity and stands for "Jurídico"
+///   or a corporate entity and stands for "Jurídico"
 /// - P: Used on RIF numbers which belongs to passports
 /// - V: Represents a person with venezuelan citizenship and stands
-/// for "Venezolana" and "Venezolano"
+///   for "Venezolana" and "Venezolano"
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Kind {
     /// E: Foreigner
     Foreigner,
@@ -63,26 +71,29 @@ impl FromStr for Kind {
     }
 }
 
-impl ToString for Kind {
-    fn to_string(&self) -> String {
-        match self {
-            Kind::Foreigner => String::from("E"),
-            Kind::Government => String::from("G"),
-            Kind::Legal => String::from("J"),
-            Kind::Passport => String::from("P"),
-            Kind::Township => String::from("C"),
-            Kind::Venezuelan => String::from("V"),
-        }
+impl core::fmt::Display for Kind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let letter = match self {
+            Kind::Foreigner => "E",
+            Kind::Government => "G",
+            Kind::Legal => "J",
+            Kind::Passport => "P",
+            Kind::Township => "C",
+            Kind::Venezuelan => "V",
+        };
+
+        write!(f, "{}", letter)
     }
 }
 
+#[cfg(all(test, feature = "std"))]
 #[allow(unused_imports)]
 mod tests {
     use super::*;
 
     #[test]
     fn creates_a_kind_instance_from_str() {
-        let kinds = vec![
+        let kinds = [
             Kind::Foreigner,
             Kind::Government,
             Kind::Legal,
@@ -109,7 +120,7 @@ mod tests {
             Kind::Venezuelan,
         ];
 
-        let string_value = vec![
+        let string_value = [
             String::from("C"),
             String::from("E"),
             String::from("G"),
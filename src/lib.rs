@@ -21,14 +21,14 @@
 //! 
 //! - C: Township or Communal Council
 //! - E: Represents a foreigner natural person and stands for
-//! "Extranjera" and "Extranjero"
+//!   "Extranjera" and "Extranjero"
 //! - G: Represents a goverment entity and stands for
-//! "Gubernamental"
+//!   "Gubernamental"
 //! - J: Used for a legal entity. Could be a natural person
-//! or a corporate entity and stands for "Jur√≠dico"
+//!   or a corporate entity and stands for "Jur√≠dico"
 //! - P: Used on RIF numbers which belongs to passports
 //! - V: Represents a person with venezuelan citizenship and stands
-//! for "Venezolana" and "Venezolano"
+//!   for "Venezolana" and "Venezolano"
 //! 
 //! An identifier number followed by a hyphen symbol and finally a checksum digit, as well followed
 //! by a hyphen symbol.
@@ -70,13 +70,43 @@
 //! assert_eq!(Rif::new(Kind::Legal, 07013380, 5).unwrap(), myrif);
 //! ```
 //!
+//! ## Features
+//!
+//! - `serde`: Implements `Serialize`/`Deserialize` for `Rif` and `Kind`. Human-readable
+//!   formats (JSON, YAML, ...) use the canonical hyphenated string (`"J-07013380-5"`),
+//!   while compact/binary formats (bincode, postcard, ...) use a tuple of the `Kind`
+//!   discriminant, the identifier and the checksum digit. Either way, decoding a `Rif`
+//!   always re-validates its checksum.
+//! - `macros`: Re-exports the `rif!` procedural macro from `rifven-macros`, which
+//!   parses and checksum-validates a RIF literal at compile time, e.g.
+//!   `rif!("J-07013380-5")`.
+//! - `std` (default): Implements this crate's `Error` against `std`. Disabling it
+//!   (`default-features = false`) builds the crate as `#![no_std]` against `alloc`
+//!   instead, for use on `wasm32-unknown-unknown` and other embedded targets. The
+//!   public API is identical either way.
+//! - `rand`: Adds `Rif::random`, generating a random, valid `Rif` of a given
+//!   `Kind` using a `rand::Rng`.
+//!
+#![cfg_attr(not(feature = "std"), no_std)]
+// RIF identifiers are canonically written with leading zeros (e.g. `07013380`);
+// these are decimal, not accidental octal-looking literals.
+#![allow(clippy::zero_prefixed_literal)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
 mod error;
 mod kind;
 mod rif;
+#[cfg(feature = "serde")]
+mod serde_impl;
 
 pub use kind::*;
 pub use rif::*;
+#[cfg(feature = "macros")]
+pub use rifven_macros::rif;
 
+#[cfg(all(test, feature = "std"))]
 #[allow(unused_imports)]
 mod tests {
     use std::str::FromStr;
@@ -112,7 +142,7 @@ mod tests {
 
     #[test]
     fn creates_rif_from_str() {
-        let candidates = vec![
+        let candidates = [
             Rif::new(Kind::Legal, 000019361, 4).unwrap(),
             Rif::new(Kind::Legal, 07013380, 5).unwrap(),
             Rif::new(Kind::Legal, 31286704, 3).unwrap(),
@@ -121,7 +151,7 @@ mod tests {
             Rif::new(Kind::Government, 20000002, 3).unwrap(),
         ];
 
-        let expects = vec![
+        let expects = [
             Rif::from_str("J-00019361-4").unwrap(),
             Rif::from_str("J-07013380-5").unwrap(),
             Rif::from_str("J-31286704-3").unwrap(),
@@ -147,7 +177,7 @@ mod tests {
             Rif::from_str("G200000040"),
         ];
 
-        let expected_error = vec![
+        let expected_error = [
           Error::UnexpectedCheckNum(5, 4),
           Error::UnexpectedCheckNum(6, 5),
           Error::InvalidRifKind(String::from("M")),
@@ -161,4 +191,96 @@ mod tests {
             assert_eq!(rif.err().unwrap(), expected_error[idx]);
         }
     }
+
+    #[test]
+    fn round_trips_through_display_and_from_str() {
+        let candidates = vec![
+            "J-00019361-4",
+            "J-07013380-5",
+            "J-31286704-3",
+            "G-20000044-9",
+            "G-20000004-0",
+            "G-20000002-3",
+        ];
+
+        for rif_str in candidates {
+            let rif = Rif::from_str(rif_str).unwrap();
+
+            assert_eq!(rif.to_string(), rif_str);
+        }
+    }
+
+    #[test]
+    fn builds_a_rif_with_a_corrected_checksum() {
+        let candidates = vec![
+            (Kind::Legal, 00019361),
+            (Kind::Legal, 07013380),
+            (Kind::Government, 20000044),
+        ];
+
+        let expects = [
+            Rif::new(Kind::Legal, 00019361, 4).unwrap(),
+            Rif::new(Kind::Legal, 07013380, 5).unwrap(),
+            Rif::new(Kind::Government, 20000044, 9).unwrap(),
+        ];
+
+        for (idx, (kind, identifier)) in candidates.into_iter().enumerate() {
+            assert_eq!(Rif::with_corrected_checksum(kind, identifier), expects[idx]);
+        }
+    }
+
+    #[test]
+    fn repairs_a_rif_with_a_mistyped_check_digit() {
+        let have = vec![
+            Rif::try_repair("J-00019361-9"),
+            Rif::try_repair("J-07013380-0"),
+            Rif::try_repair("G-20000044-0"),
+        ];
+
+        let expects = [
+            Rif::new(Kind::Legal, 00019361, 4).unwrap(),
+            Rif::new(Kind::Legal, 07013380, 5).unwrap(),
+            Rif::new(Kind::Government, 20000044, 9).unwrap(),
+        ];
+
+        for (idx, rif) in have.into_iter().enumerate() {
+            assert_eq!(rif.unwrap(), expects[idx]);
+        }
+    }
+
+    #[test]
+    fn try_repair_still_rejects_a_malformed_rif() {
+        assert_eq!(
+            Rif::try_repair("J200000040").err().unwrap(),
+            Error::InvalidRif(String::from("RIF must be splitted into 3 parts separated by dashes. Eg. J-123456789-1. Provided J200000040")),
+        );
+        assert_eq!(
+            Rif::try_repair("M-00000001-3").err().unwrap(),
+            Error::InvalidRifKind(String::from("M")),
+        );
+    }
+
+    #[test]
+    fn iter_kind_yields_every_valid_rif_in_order() {
+        let mut rifs = Rif::iter_kind(Kind::Legal);
+
+        assert_eq!(rifs.next(), Some(Rif::new(Kind::Legal, 0, 0).unwrap()));
+        assert_eq!(rifs.next(), Some(Rif::new(Kind::Legal, 1, 8).unwrap()));
+    }
+
+    #[test]
+    fn iter_kind_round_trips_through_the_checksum_boundary() {
+        // Identifier 0 makes `calc_checksum_digit`'s reminder equal 1
+        // (11 - 1 = 10) and identifier 5 makes it equal 0 (11 - 0 = 11).
+        // Both outcomes are collapsed onto checksum digit 0 and must
+        // still round-trip through `FromStr`.
+        let boundary_identifiers = vec![0, 5];
+
+        for identifier in boundary_identifiers {
+            let rif = Rif::with_corrected_checksum(Kind::Legal, identifier);
+
+            assert_eq!(rif.checksum_digit(), 0);
+            assert_eq!(Rif::from_str(&rif.to_string()).unwrap(), rif);
+        }
+    }
 }
@@ -1,11 +1,30 @@
+#[cfg(feature = "std")]
 use std::ops::IndexMut;
+#[cfg(feature = "std")]
 use std::str::FromStr;
+#[cfg(feature = "std")]
+use std::string::ToString;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use alloc::format;
+#[cfg(not(feature = "std"))]
+use alloc::string::ToString;
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use core::ops::IndexMut;
+#[cfg(not(feature = "std"))]
+use core::str::FromStr;
 
 use crate::error::{Error, Result};
 use crate::kind::Kind;
 
 /// Length of a RIF identifier
-const RIF_IDENTIFIER_LENGTH: usize = 9;
+pub(crate) const RIF_IDENTIFIER_LENGTH: usize = 9;
 
 /// Venezuelan RIF identifier
 ///
@@ -125,7 +144,7 @@ impl Rif {
             .unwrap()
             .parse::<u8>()
             .map_err(|_| Error::InvalidCheckNum(parts.get(2).unwrap().to_string()))?;
-        let kind = Kind::from_str(parts.get(0).unwrap())?;
+        let kind = Kind::from_str(parts.first().unwrap())?;
         let identifier = {
             let identifier = parts.get(1).unwrap();
 
@@ -150,6 +169,13 @@ impl Rif {
     }
 
     /// Calculates the **checksum_digit** for a provided RIF number
+    ///
+    /// Note the arithmetic here collapses two distinct outcomes onto the
+    /// same digit: when `11 - reminder` is `10` or `11` (i.e. `reminder`
+    /// is `1` or `0`), both are reported as checksum digit `0`. This is
+    /// part of the official algorithm, not a bug, and every digit it
+    /// produces (including these two cases) still round-trips through
+    /// `FromStr`.
     pub fn calc_checksum_digit(kind: &Kind, identifier: u32) -> u8 {
         let mut digits: Vec<u32> = vec![0; RIF_IDENTIFIER_LENGTH];
         let mut sum_values: Vec<u32> = vec![0; RIF_IDENTIFIER_LENGTH];
@@ -186,12 +212,134 @@ impl Rif {
 
         checksum_digit as u8
     }
+
+    /// Constructs a `Rif` for `kind`/`identifier` using the checksum digit
+    /// `calc_checksum_digit` computes for them, so unlike `Rif::new` this
+    /// never fails.
+    ///
+    /// ```rust
+    /// use rifven::{Kind, Rif};
+    ///
+    /// let rif = Rif::with_corrected_checksum(Kind::Legal, 07013380);
+    ///
+    /// assert_eq!(rif, Rif::new(Kind::Legal, 07013380, 5).unwrap());
+    /// ```
+    pub fn with_corrected_checksum(kind: Kind, identifier: u32) -> Rif {
+        let checksum_digit = Rif::calc_checksum_digit(&kind, identifier);
+
+        Rif {
+            checksum_digit,
+            identifier,
+            kind,
+        }
+    }
+
+    /// Parses `s` the same way `FromStr` does, but repairs a mistyped or
+    /// omitted check digit instead of rejecting it, trusting the `kind`
+    /// and identifier body. Useful for data-cleaning pipelines importing
+    /// legacy records whose check digit can't be relied upon.
+    ///
+    /// The third hyphen-separated part is ignored but must be present:
+    /// `"J-07013380-"` is repaired, while `"J-07013380"` is rejected
+    /// with `Error::InvalidRif`.
+    ///
+    /// ```rust
+    /// use rifven::{Kind, Rif};
+    ///
+    /// let repaired = Rif::try_repair("J-07013380-0").unwrap();
+    ///
+    /// assert_eq!(repaired, Rif::new(Kind::Legal, 07013380, 5).unwrap());
+    /// ```
+    pub fn try_repair(s: &str) -> Result<Rif> {
+        let parts: Vec<&str> = s.split('-').collect();
+
+        if parts.len() != 3 {
+            return Err(Error::InvalidRif(format!("RIF must be splitted into 3 parts separated by dashes. Eg. J-123456789-1. Provided {}", s)));
+        }
+
+        let kind = Kind::from_str(parts.first().unwrap())?;
+        let identifier = parts
+            .get(1)
+            .unwrap()
+            .parse::<u32>()
+            .map_err(|e| Error::InvalidRifIdentifier(e.to_string()))?;
+
+        Ok(Rif::with_corrected_checksum(kind, identifier))
+    }
+
+    /// Iterates over every valid `Rif` of the given `kind` whose identifier
+    /// fits the 8-digit body (`0` to `99_999_999`), in ascending identifier
+    /// order, computing each checksum via `calc_checksum_digit`.
+    /// Every `Rif` this yields round-trips through `FromStr`, including the
+    /// identifiers where `calc_checksum_digit` collapses its `10`/`11`
+    /// outcomes onto checksum digit `0`.
+    ///
+    /// ```rust
+    /// use rifven::{Kind, Rif};
+    ///
+    /// let mut rifs = Rif::iter_kind(Kind::Legal);
+    ///
+    /// assert_eq!(rifs.next(), Some(Rif::with_corrected_checksum(Kind::Legal, 0)));
+    /// assert_eq!(rifs.next(), Some(Rif::with_corrected_checksum(Kind::Legal, 1)));
+    /// ```
+    pub fn iter_kind(kind: Kind) -> impl Iterator<Item = Rif> {
+        let max_identifier = 10u32.pow((RIF_IDENTIFIER_LENGTH - 1) as u32) - 1;
+
+        (0..=max_identifier).map(move |identifier| Rif::with_corrected_checksum(kind.clone(), identifier))
+    }
+
+    /// Generates a random, valid `Rif` of the given `kind` using `rng`.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "rand")]
+    /// # {
+    /// use rand::thread_rng;
+    /// use rifven::{Kind, Rif};
+    ///
+    /// let rif = Rif::random(Kind::Legal, &mut thread_rng());
+    ///
+    /// assert_eq!(rif.kind(), Kind::Legal);
+    /// # }
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random(kind: Kind, rng: &mut impl rand::Rng) -> Rif {
+        let max_identifier = 10u32.pow((RIF_IDENTIFIER_LENGTH - 1) as u32) - 1;
+        let identifier = rng.gen_range(0..=max_identifier);
+
+        Rif::with_corrected_checksum(kind, identifier)
+    }
 }
 
 impl FromStr for Rif {
     type Err = Error;
 
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        Ok(Rif::validate(s)?)
+    fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+        Rif::validate(s)
+    }
+}
+
+impl core::fmt::Display for Rif {
+    /// Formats the `Rif` back into its canonical hyphenated string,
+    /// re-padding the identifier with leading zeros to the width
+    /// `calc_checksum_digit` expects. `FromStr` parses the result back
+    /// into an equal `Rif`; a string with an upper-case kind and an
+    /// 8-digit identifier formats back to itself.
+    ///
+    /// ```rust
+    /// use rifven::{Kind, Rif};
+    ///
+    /// let rif = Rif::new(Kind::Legal, 00019361, 4).unwrap();
+    ///
+    /// assert_eq!(rif.to_string(), "J-00019361-4");
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}-{:0width$}-{}",
+            self.kind,
+            self.identifier,
+            self.checksum_digit,
+            width = RIF_IDENTIFIER_LENGTH - 1,
+        )
     }
 }
@@ -0,0 +1,166 @@
+//! `serde` support for [`Rif`].
+//!
+//! Human-readable formats (JSON, YAML, ...) encode a `Rif` using its
+//! canonical hyphenated string, e.g. `"J-07013380-5"`. Compact/binary
+//! formats (bincode, postcard, ...) instead encode a tuple of the `Kind`
+//! discriminant (one byte), the `u32` identifier and the `u8` checksum
+//! digit, avoiding the cost of formatting/parsing the string on the wire.
+//!
+//! Either way, decoding always routes through [`Rif::new`]/[`Rif::from_str`]
+//! so a tampered checksum is rejected at deserialization time instead of
+//! producing a structurally-valid-but-invalid `Rif`.
+#[cfg(feature = "std")]
+use std::fmt;
+#[cfg(feature = "std")]
+use std::str::FromStr;
+#[cfg(feature = "std")]
+use std::string::{String, ToString};
+
+#[cfg(not(feature = "std"))]
+use alloc::string::{String, ToString};
+#[cfg(not(feature = "std"))]
+use core::fmt;
+#[cfg(not(feature = "std"))]
+use core::str::FromStr;
+
+use serde::de::{self, SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::Error;
+use crate::kind::Kind;
+use crate::rif::Rif;
+
+impl Kind {
+    fn to_discriminant(&self) -> u8 {
+        match self {
+            Kind::Township => 0,
+            Kind::Foreigner => 1,
+            Kind::Government => 2,
+            Kind::Legal => 3,
+            Kind::Passport => 4,
+            Kind::Venezuelan => 5,
+        }
+    }
+
+    fn from_discriminant(discriminant: u8) -> Result<Self, Error> {
+        match discriminant {
+            0 => Ok(Kind::Township),
+            1 => Ok(Kind::Foreigner),
+            2 => Ok(Kind::Government),
+            3 => Ok(Kind::Legal),
+            4 => Ok(Kind::Passport),
+            5 => Ok(Kind::Venezuelan),
+            _ => Err(Error::InvalidRifKind(discriminant.to_string())),
+        }
+    }
+}
+
+impl Serialize for Rif {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_string())
+        } else {
+            let mut tuple = serializer.serialize_tuple(3)?;
+            tuple.serialize_element(&self.kind.to_discriminant())?;
+            tuple.serialize_element(&self.identifier)?;
+            tuple.serialize_element(&self.checksum_digit)?;
+            tuple.end()
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Rif {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            let raw = String::deserialize(deserializer)?;
+
+            Rif::from_str(&raw).map_err(de::Error::custom)
+        } else {
+            struct RifVisitor;
+
+            impl<'de> Visitor<'de> for RifVisitor {
+                type Value = Rif;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    formatter.write_str("a (kind discriminant, identifier, checksum digit) tuple")
+                }
+
+                fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                where
+                    A: SeqAccess<'de>,
+                {
+                    let discriminant: u8 = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                    let identifier: u32 = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                    let checksum_digit: u8 = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                    let kind = Kind::from_discriminant(discriminant).map_err(de::Error::custom)?;
+
+                    Rif::new(kind, identifier, checksum_digit).map_err(de::Error::custom)
+                }
+            }
+
+            deserializer.deserialize_tuple(3, RifVisitor)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde", feature = "std"))]
+#[allow(unused_imports)]
+mod tests {
+    use crate::kind::Kind;
+    use crate::rif::Rif;
+
+    #[test]
+    fn json_round_trips_through_the_canonical_string() {
+        let rif = Rif::new(Kind::Legal, 07013380, 5).unwrap();
+        let json = serde_json::to_string(&rif).unwrap();
+
+        assert_eq!(json, "\"J-07013380-5\"");
+        assert_eq!(serde_json::from_str::<Rif>(&json).unwrap(), rif);
+    }
+
+    #[test]
+    fn bincode_round_trips_through_the_compact_tuple() {
+        let rif = Rif::new(Kind::Legal, 07013380, 5).unwrap();
+        let bytes = bincode::serialize(&rif).unwrap();
+
+        assert_eq!(
+            bincode::deserialize::<(u8, u32, u8)>(&bytes).unwrap(),
+            (3, 7013380, 5),
+        );
+        assert_eq!(bincode::deserialize::<Rif>(&bytes).unwrap(), rif);
+    }
+
+    #[test]
+    fn json_rejects_a_tampered_checksum() {
+        let rif = Rif::new(Kind::Legal, 07013380, 5).unwrap();
+        let tampered = serde_json::to_string(&rif)
+            .unwrap()
+            .replace("5\"", "4\"");
+
+        assert!(serde_json::from_str::<Rif>(&tampered).is_err());
+    }
+
+    #[test]
+    fn bincode_rejects_a_tampered_checksum() {
+        let rif = Rif::new(Kind::Legal, 07013380, 5).unwrap();
+        let mut bytes = bincode::serialize(&rif).unwrap();
+        let last = bytes.len() - 1;
+
+        bytes[last] = 4;
+
+        assert!(bincode::deserialize::<Rif>(&bytes).is_err());
+    }
+}
@@ -0,0 +1,12 @@
+//! Locks the `rif!` macro's checksum routine to `Rif::calc_checksum_digit`:
+//! a valid literal must expand, and a mistyped check digit must fail to
+//! compile with the same wording `rifven::Error` raises at runtime.
+#![cfg(feature = "macros")]
+
+#[test]
+fn rif_macro() {
+    let t = trybuild::TestCases::new();
+
+    t.pass("tests/trybuild/pass/*.rs");
+    t.compile_fail("tests/trybuild/fail/*.rs");
+}
@@ -0,0 +1,5 @@
+use rifven::rif;
+
+fn main() {
+    let _rif = rif!("J-07013380-4");
+}
@@ -0,0 +1,9 @@
+use rifven::{rif, Kind};
+
+fn main() {
+    let rif = rif!("J-07013380-5");
+
+    assert_eq!(rif.kind(), Kind::Legal);
+    assert_eq!(rif.identifier(), 7013380);
+    assert_eq!(rif.checksum_digit(), 5);
+}